@@ -12,30 +12,48 @@
 #   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 #
 
-message(STATUS "Generating ${cxxbridge_in}")
+#
+# Generates the bridge of the glue, `LIB_RS` (`vendor/wrapper/src/lib.rs`),
+# from its fragments in `BRIDGE_DIR` (`vendor/wrapper/bridge`):
+# - `header.in`: the documentation, the modules and the opening of the bridge;
+# - `shared-structs.in`: the structs shared by the kinds of node;
+# - `<kind>-cpp-interface.in`: the structs and the C++ callbacks of a kind of
+#   node;
+# - `zenoh-flow-glue.in`: the functions of the glue exposed to C++, the end of
+#   the bridge and the conversions shared by the kinds of node;
+# - `<kind>-impl.in`: the module implementing the Zenoh Flow trait of a kind of
+#   node.
+#
+# The glue is a single crate: the fragments of every kind of node are included,
+# gated by its feature. The generated file is in the source tree, for the
+# builds with cargo alone.
+#
+# cmake -DBRIDGE_DIR=vendor/wrapper/bridge -DLIB_RS=vendor/wrapper/src/lib.rs -P cmake/GenerateLibRs.cmake
+#
 
-# NOTE: the order is important!
-set(1_header_in          ${include_dir}/${component}-header.in)
-set(2_shared_structs_in  ${include_dir}/shared-structs.in)
-set(3_cpp_interface_in   ${include_dir}/${component}-cpp-interface.in)
-set(4_zenoh_flow_glue_in ${include_dir}/zenoh-flow-glue.in)
-set(5_implementation_in  ${include_dir}/${component}-impl.in)
+message(STATUS "Generating ${LIB_RS}")
 
-# Create the list of files to concatenate in order to produce lib/src.rs.
-set(lib_src_parts
-  ${1_header_in}
-  ${2_shared_structs_in}
-  ${3_cpp_interface_in}
-  ${4_zenoh_flow_glue_in}
-  ${5_implementation_in})
+set(kinds source operator sink)
 
-# Empties the content of ${cxxbridge_in}.
-file(WRITE ${cxxbridge_in} "")
+# NOTE: the order is important!
+set(lib_src_parts ${BRIDGE_DIR}/header.in ${BRIDGE_DIR}/shared-structs.in)
+foreach(kind ${kinds})
+  list(APPEND lib_src_parts ${BRIDGE_DIR}/${kind}-cpp-interface.in)
+endforeach()
+list(APPEND lib_src_parts ${BRIDGE_DIR}/zenoh-flow-glue.in)
+foreach(kind ${kinds})
+  list(APPEND lib_src_parts ${BRIDGE_DIR}/${kind}-impl.in)
+endforeach()
 
-# Appends the contents of each file and generate `src/lib.rs`.
+# Concatenates the fragments, then replaces the bridge only if it changed so
+# that cargo does not rebuild the glue for nothing.
+set(lib_rs_tmp ${LIB_RS}.tmp)
+file(WRITE ${lib_rs_tmp} "")
 foreach(part ${lib_src_parts})
   file(READ ${part} contents)
-  file(APPEND ${cxxbridge_in} "${contents}")
+  file(APPEND ${lib_rs_tmp} "${contents}")
 endforeach()
+execute_process(COMMAND ${CMAKE_COMMAND} -E copy_if_different ${lib_rs_tmp} ${LIB_RS})
+file(REMOVE ${lib_rs_tmp})
 
-message(STATUS "Generating ${cxxbridge_in} - done")
+message(STATUS "Generating ${LIB_RS} - done")
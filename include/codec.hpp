@@ -0,0 +1,151 @@
+//
+// Copyright (c) 2022 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+//
+// Encoding of the data of the typed ports, see `zf-cxx generate`.
+//
+// The integers are encoded in little endian on their exact width, the floating
+// point numbers as the bits of their IEEE 754 representation, the booleans on
+// one byte, the strings as their bytes and the raw bytes as is. The Rust
+// modules generated along with the headers use the same encoding.
+//
+// /!\ NOTE: this header is included by the generated headers, after the header
+// of the node (e.g. `operator.hpp`), which declares the bridge.
+//
+
+#pragma once
+#include <cstdint>
+#include <cstring>
+#include <string>
+#include <type_traits>
+
+#include <errors.hpp>
+#include <wrapper.hpp>
+
+namespace zenoh {
+namespace flow {
+namespace codec {
+
+using Bytes = ::rust::Vec<std::uint8_t>;
+
+/**
+ * Throws a `RecoverableError` if the data received on `port` is not `size`
+ * bytes long: the input is dropped.
+ */
+inline void check_size(const Bytes &data, std::size_t size, const char *port) {
+  if (data.size() != size) {
+    throw RecoverableError("`" + std::string(port) + "`: expected " +
+                           std::to_string(size) + " byte(s), got " +
+                           std::to_string(data.size()));
+  }
+}
+
+template <typename T, typename Enable = void> struct Codec;
+
+template <typename T>
+struct Codec<T, typename std::enable_if<std::is_integral<T>::value &&
+                                        !std::is_same<T, bool>::value>::type> {
+  using Bits = typename std::make_unsigned<T>::type;
+
+  static Bytes encode(T value) {
+    Bits bits = static_cast<Bits>(value);
+    Bytes data;
+    data.reserve(sizeof(T));
+    for (std::size_t i = 0; i < sizeof(T); ++i) {
+      data.push_back(static_cast<std::uint8_t>(bits >> (8 * i)));
+    }
+    return data;
+  }
+
+  static T decode(const Bytes &data, const char *port) {
+    check_size(data, sizeof(T), port);
+    Bits bits = 0;
+    for (std::size_t i = 0; i < sizeof(T); ++i) {
+      bits = static_cast<Bits>(bits | (static_cast<Bits>(data[i]) << (8 * i)));
+    }
+    return static_cast<T>(bits);
+  }
+};
+
+template <> struct Codec<bool> {
+  static Bytes encode(bool value) {
+    return Bytes{static_cast<std::uint8_t>(value ? 1 : 0)};
+  }
+
+  static bool decode(const Bytes &data, const char *port) {
+    check_size(data, 1, port);
+    return data[0] != 0;
+  }
+};
+
+template <typename T, typename Bits> struct FloatCodec {
+  static_assert(sizeof(T) == sizeof(Bits),
+                "The floating point numbers must be IEEE 754.");
+
+  static Bytes encode(T value) {
+    Bits bits;
+    std::memcpy(&bits, &value, sizeof(bits));
+    return Codec<Bits>::encode(bits);
+  }
+
+  static T decode(const Bytes &data, const char *port) {
+    Bits bits = Codec<Bits>::decode(data, port);
+    T value;
+    std::memcpy(&value, &bits, sizeof(value));
+    return value;
+  }
+};
+
+template <> struct Codec<float> : FloatCodec<float, std::uint32_t> {};
+template <> struct Codec<double> : FloatCodec<double, std::uint64_t> {};
+
+template <> struct Codec<std::string> {
+  static Bytes encode(const std::string &value) {
+    Bytes data;
+    data.reserve(value.size());
+    for (char c : value) {
+      data.push_back(static_cast<std::uint8_t>(c));
+    }
+    return data;
+  }
+
+  static std::string decode(const Bytes &data, const char *) {
+    return std::string(reinterpret_cast<const char *>(data.data()), data.size());
+  }
+};
+
+template <> struct Codec<Bytes> {
+  static Bytes encode(const Bytes &value) { return value; }
+  static Bytes decode(const Bytes &data, const char *) { return data; }
+};
+
+/**
+ * Encodes `value`, to be sent on a port.
+ */
+template <typename T> Bytes encode(const T &value) {
+  return Codec<T>::encode(value);
+}
+
+/**
+ * Decodes the data received on `port`.
+ *
+ * Throws a `RecoverableError` if the data is malformed.
+ */
+template <typename T> T decode(const Bytes &data, const char *port) {
+  return Codec<T>::decode(data, port);
+}
+
+} // namespace codec
+} // namespace flow
+} // namespace zenoh
@@ -0,0 +1,65 @@
+//
+// Copyright (c) 2022 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+#pragma once
+#include <exception>
+#include <stdexcept>
+#include <string>
+
+namespace zenoh {
+namespace flow {
+
+/**
+ * Thrown by a node when the current input cannot be processed but the node
+ * can carry on, e.g. malformed data: the input is dropped.
+ */
+class RecoverableError : public std::runtime_error {
+public:
+  using std::runtime_error::runtime_error;
+};
+
+/**
+ * Thrown by a node when it cannot carry on, e.g. its device is gone: the node
+ * is finalized and the error is propagated to Zenoh Flow.
+ */
+class FatalError : public std::runtime_error {
+public:
+  using std::runtime_error::runtime_error;
+};
+
+} // namespace flow
+} // namespace zenoh
+
+//
+// Customization of the exception handling of the bridge: the kind of error is
+// transmitted to the Rust glue as a prefix of the message.
+//
+// /!\ NOTE: the prefixes must match the ones in `vendor/wrapper/src/error.rs`.
+//
+namespace rust {
+namespace behavior {
+
+template <typename Try, typename Fail>
+static void trycatch(Try &&func, Fail &&fail) noexcept try {
+  func();
+} catch (const zenoh::flow::RecoverableError &e) {
+  fail((std::string("[recoverable] ") + e.what()).c_str());
+} catch (const zenoh::flow::FatalError &e) {
+  fail((std::string("[fatal] ") + e.what()).c_str());
+} catch (const std::exception &e) {
+  fail(e.what());
+}
+
+} // namespace behavior
+} // namespace rust
@@ -0,0 +1,212 @@
+//
+// Copyright (c) 2022 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+//
+// Driver of a C++ node built against the mock bridge (`wrapper.hpp`), to test
+// it with any C++ test framework, without Zenoh Flow nor Rust.
+//
+// The drivers call the functions of the node as the glue would, and the mock
+// records what the node did through the functions of the glue (logs, custom
+// metrics) and controls what it sees (clock, end-to-end latency).
+//
+// /!\ NOTE: the state of the mock is global and not thread-safe, call `reset`
+// between the tests.
+//
+
+#pragma once
+#include <cstdint>
+#include <map>
+#include <memory>
+#include <string>
+#include <vector>
+
+#include <wrapper.hpp>
+
+#if defined(ZENOH_FLOW_OPERATOR)
+#include <operator.hpp>
+#elif defined(ZENOH_FLOW_SOURCE)
+#include <source.hpp>
+#elif defined(ZENOH_FLOW_SINK)
+#include <sink.hpp>
+#endif
+
+namespace zenoh {
+namespace flow {
+namespace mock {
+
+/*
+ *
+ * Mock of the glue.
+ *
+ */
+
+enum class Level { Error, Warn, Info, Debug, Trace };
+
+/**
+ * A message logged by the node through `zenoh::flow::log`.
+ */
+struct Record {
+  Level level;
+  std::string message;
+};
+
+/**
+ * The messages logged by the node, in order.
+ */
+const std::vector<Record> &logs();
+
+/**
+ * Sets the time returned by `clock::now`, in nanoseconds since the UNIX epoch.
+ * The clock of the mock is simulated: it starts at 0 and only changes when it
+ * is set or advanced.
+ */
+void set_time_ns(std::uint64_t nanos);
+
+/**
+ * Advances the time returned by `clock::now` by `nanos`.
+ */
+void advance_ns(std::uint64_t nanos);
+
+/**
+ * The value of a custom metric registered by the node.
+ *
+ * Throws `std::out_of_range` if it is not registered.
+ */
+double metric(const std::string &name);
+
+#if defined(ZENOH_FLOW_SINK)
+/**
+ * Sets the value returned by `end_to_end_latency`.
+ */
+void set_end_to_end_latency(Latency latency);
+#endif
+
+/**
+ * Resets the mock: clears the logs and the metrics, sets the time to 0.
+ */
+void reset();
+
+/*
+ *
+ * Drivers.
+ *
+ */
+
+#if defined(ZENOH_FLOW_OPERATOR)
+
+/**
+ * The data produced on each output port.
+ */
+using Outputs = std::map<std::string, std::vector<std::uint8_t>>;
+
+/**
+ * Drives a C++ operator.
+ */
+class OperatorDriver {
+public:
+  /**
+   * Initializes the operator, whose input ports are `inputs`, with the JSON
+   * `configuration`.
+   */
+  explicit OperatorDriver(std::vector<std::string> inputs,
+                          const std::string &configuration = "{}");
+
+  Context &context() { return context_; }
+  std::unique_ptr<State> &state() { return state_; }
+
+  /**
+   * Receives `data` on `port`, as the runtime would: the input rule is called
+   * with the data received since the last run and, if it triggers the run, the
+   * run and the output rule are called with them.
+   *
+   * Returns whether the run was triggered, the data produced is then given by
+   * `outputs`. The data is stamped with `timestamp`, the current time of the
+   * mock by default.
+   *
+   * Throws `std::invalid_argument` if `port` is not an input of the operator.
+   */
+  bool receive(const std::string &port, std::vector<std::uint8_t> data);
+  bool receive(const std::string &port, std::vector<std::uint8_t> data,
+               std::uint64_t timestamp);
+
+  /**
+   * The data produced by the last run.
+   */
+  const Outputs &outputs() const { return outputs_; }
+
+private:
+  Context context_;
+  std::unique_ptr<State> state_;
+  std::vector<std::string> inputs_;
+  std::map<std::string, Input> received_;
+  Outputs outputs_;
+};
+
+#elif defined(ZENOH_FLOW_SOURCE)
+
+/**
+ * Drives a C++ source.
+ */
+class SourceDriver {
+public:
+  /**
+   * Initializes the source with the JSON `configuration`.
+   */
+  explicit SourceDriver(const std::string &configuration = "{}");
+
+  Context &context() { return context_; }
+  std::unique_ptr<State> &state() { return state_; }
+
+  /**
+   * Runs the source once and returns the data it produced.
+   */
+  std::vector<std::uint8_t> run();
+
+private:
+  Context context_;
+  std::unique_ptr<State> state_;
+};
+
+#elif defined(ZENOH_FLOW_SINK)
+
+/**
+ * Drives a C++ sink.
+ */
+class SinkDriver {
+public:
+  /**
+   * Initializes the sink with the JSON `configuration`.
+   */
+  explicit SinkDriver(const std::string &configuration = "{}");
+
+  Context &context() { return context_; }
+  std::unique_ptr<State> &state() { return state_; }
+
+  /**
+   * Gives `data` to the sink, stamped with `timestamp`, the current time of
+   * the mock by default.
+   */
+  void run(std::vector<std::uint8_t> data);
+  void run(std::vector<std::uint8_t> data, std::uint64_t timestamp);
+
+private:
+  Context context_;
+  std::unique_ptr<State> state_;
+};
+
+#endif
+
+} // namespace mock
+} // namespace flow
+} // namespace zenoh
@@ -0,0 +1,306 @@
+//
+// Copyright (c) 2022 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+//
+// Mock of the header generated by `cxxbridge` (`include/wrapper.hpp`).
+//
+// It declares the same types and functions as the bridge of the node, without
+// Rust: `rust::Str`, `rust::String` and `rust::Vec` are backed by the standard
+// library and the functions exposed by the glue (logging, clock, metrics,
+// metadata) are implemented by the mock library, see `mock.hpp`.
+//
+// The kind of node is selected by defining `ZENOH_FLOW_OPERATOR`,
+// `ZENOH_FLOW_SOURCE` or `ZENOH_FLOW_SINK`.
+//
+// /!\ NOTE: the types must match the bridge in `vendor/wrapper/src/lib.rs`.
+//
+
+#pragma once
+#include <cstddef>
+#include <cstdint>
+#include <cstring>
+#include <exception>
+#include <initializer_list>
+#include <memory>
+#include <ostream>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include <errors.hpp>
+
+#if !defined(ZENOH_FLOW_OPERATOR) && !defined(ZENOH_FLOW_SOURCE) &&            \
+    !defined(ZENOH_FLOW_SINK)
+#error "The kind of node must be defined (i.e., ZENOH_FLOW_OPERATOR, ZENOH_FLOW_SOURCE, ZENOH_FLOW_SINK)."
+#endif
+
+namespace rust {
+inline namespace cxxbridge1 {
+
+class Str;
+
+/**
+ * An owned string, as `rust::String`.
+ */
+class String final {
+public:
+  String() noexcept = default;
+  String(const std::string &s) : repr(s) {}
+  String(const char *s) : repr(s) {}
+  String(const char *s, std::size_t len) : repr(s, len) {}
+  String(Str s);
+
+  explicit operator std::string() const { return repr; }
+
+  const char *data() const noexcept { return repr.data(); }
+  std::size_t size() const noexcept { return repr.size(); }
+  std::size_t length() const noexcept { return repr.length(); }
+  bool empty() const noexcept { return repr.empty(); }
+  const char *c_str() noexcept { return repr.c_str(); }
+
+  using iterator = std::string::iterator;
+  using const_iterator = std::string::const_iterator;
+  iterator begin() noexcept { return repr.begin(); }
+  iterator end() noexcept { return repr.end(); }
+  const_iterator begin() const noexcept { return repr.begin(); }
+  const_iterator end() const noexcept { return repr.end(); }
+  const_iterator cbegin() const noexcept { return repr.cbegin(); }
+  const_iterator cend() const noexcept { return repr.cend(); }
+
+  bool operator==(const String &other) const noexcept { return repr == other.repr; }
+  bool operator!=(const String &other) const noexcept { return repr != other.repr; }
+  bool operator<(const String &other) const noexcept { return repr < other.repr; }
+
+private:
+  std::string repr;
+};
+
+/**
+ * A borrowed string, as `rust::Str`.
+ */
+class Str final {
+public:
+  Str() noexcept : ptr(""), len(0) {}
+  Str(const String &s) noexcept : ptr(s.data()), len(s.size()) {}
+  Str(const std::string &s) noexcept : ptr(s.data()), len(s.size()) {}
+  Str(const char *s) : ptr(s), len(std::strlen(s)) {}
+  Str(const char *s, std::size_t len) : ptr(s), len(len) {}
+
+  explicit operator std::string() const { return std::string(ptr, len); }
+
+  const char *data() const noexcept { return ptr; }
+  std::size_t size() const noexcept { return len; }
+  std::size_t length() const noexcept { return len; }
+  bool empty() const noexcept { return len == 0; }
+
+  using iterator = const char *;
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept { return ptr; }
+  const_iterator end() const noexcept { return ptr + len; }
+  const_iterator cbegin() const noexcept { return ptr; }
+  const_iterator cend() const noexcept { return ptr + len; }
+
+  bool operator==(const Str &other) const noexcept {
+    return len == other.len && std::memcmp(ptr, other.ptr, len) == 0;
+  }
+  bool operator!=(const Str &other) const noexcept { return !(*this == other); }
+
+private:
+  const char *ptr;
+  std::size_t len;
+};
+
+inline String::String(Str s) : repr(s.data(), s.size()) {}
+
+inline std::ostream &operator<<(std::ostream &os, const String &s) {
+  return os.write(s.data(), s.size());
+}
+
+inline std::ostream &operator<<(std::ostream &os, const Str &s) {
+  return os.write(s.data(), s.size());
+}
+
+/**
+ * A vector, as `rust::Vec`: only its API is available.
+ */
+template <typename T> class Vec final {
+public:
+  using value_type = T;
+  using iterator = typename std::vector<T>::iterator;
+  using const_iterator = typename std::vector<T>::const_iterator;
+
+  Vec() noexcept = default;
+  Vec(std::initializer_list<T> init) : repr(init) {}
+
+  std::size_t size() const noexcept { return repr.size(); }
+  bool empty() const noexcept { return repr.empty(); }
+  const T *data() const noexcept { return repr.data(); }
+  T *data() noexcept { return repr.data(); }
+  std::size_t capacity() const noexcept { return repr.capacity(); }
+
+  const T &operator[](std::size_t n) const noexcept { return repr[n]; }
+  T &operator[](std::size_t n) noexcept { return repr[n]; }
+  const T &at(std::size_t n) const { return repr.at(n); }
+  T &at(std::size_t n) { return repr.at(n); }
+  const T &front() const noexcept { return repr.front(); }
+  T &front() noexcept { return repr.front(); }
+  const T &back() const noexcept { return repr.back(); }
+  T &back() noexcept { return repr.back(); }
+
+  void reserve(std::size_t new_cap) { repr.reserve(new_cap); }
+  void push_back(const T &value) { repr.push_back(value); }
+  void push_back(T &&value) { repr.push_back(std::move(value)); }
+  template <typename... Args> void emplace_back(Args &&...args) {
+    repr.emplace_back(std::forward<Args>(args)...);
+  }
+  void truncate(std::size_t len) {
+    if (len < repr.size()) {
+      repr.resize(len);
+    }
+  }
+  void clear() { repr.clear(); }
+  void swap(Vec &other) noexcept { repr.swap(other.repr); }
+
+  iterator begin() noexcept { return repr.begin(); }
+  iterator end() noexcept { return repr.end(); }
+  const_iterator begin() const noexcept { return repr.begin(); }
+  const_iterator end() const noexcept { return repr.end(); }
+  const_iterator cbegin() const noexcept { return repr.cbegin(); }
+  const_iterator cend() const noexcept { return repr.cend(); }
+
+private:
+  std::vector<T> repr;
+};
+
+/**
+ * The exception thrown by the fallible functions of the glue, as
+ * `rust::Error`.
+ */
+class Error final : public std::exception {
+public:
+  explicit Error(std::string message) : message(std::move(message)) {}
+  const char *what() const noexcept override { return message.c_str(); }
+
+private:
+  std::string message;
+};
+
+} // namespace cxxbridge1
+} // namespace rust
+
+namespace zenoh {
+namespace flow {
+
+struct Context final {
+  std::size_t mode;
+};
+
+#if defined(ZENOH_FLOW_OPERATOR) || defined(ZENOH_FLOW_SINK)
+struct OutputDescriptor final {
+  ::rust::String node;
+  ::rust::String output;
+};
+
+struct InputDescriptor final {
+  ::rust::String node;
+  ::rust::String input;
+};
+
+struct E2EDeadlineMiss final {
+  ::zenoh::flow::OutputDescriptor from;
+  ::zenoh::flow::InputDescriptor to;
+  std::uint64_t start;
+  std::uint64_t end;
+};
+
+struct Input final {
+  ::rust::String port_id;
+  ::rust::Vec<std::uint8_t> data;
+  std::uint64_t timestamp;
+  ::rust::Vec<::zenoh::flow::E2EDeadlineMiss> e2d_deadline_miss;
+};
+#endif
+
+#if defined(ZENOH_FLOW_OPERATOR)
+struct Output final {
+  ::rust::String port_id;
+  ::rust::Vec<std::uint8_t> data;
+};
+
+enum class TokenStatus : std::uint8_t {
+  Pending = 0,
+  Ready = 1,
+};
+
+enum class TokenAction : std::uint8_t {
+  Consume = 0,
+  Drop = 1,
+  Keep = 2,
+  Wait = 3,
+};
+
+struct LocalDeadlineMiss final {
+  std::uint64_t elapsed_ms;
+  std::uint64_t deadline_duration_ms;
+  bool is_set;
+};
+
+struct InputToken final {
+  ::zenoh::flow::TokenStatus status;
+  ::zenoh::flow::TokenAction action;
+  ::rust::String port_id;
+  ::rust::Vec<std::uint8_t> data;
+  std::uint64_t timestamp;
+};
+#endif
+
+#if defined(ZENOH_FLOW_SINK)
+struct Latency final {
+  std::uint64_t count;
+  std::uint64_t p50_ns;
+  std::uint64_t p99_ns;
+  std::uint64_t max_ns;
+};
+
+::zenoh::flow::Latency end_to_end_latency();
+#endif
+
+namespace log {
+void error(::rust::Str message) noexcept;
+void warn(::rust::Str message) noexcept;
+void info(::rust::Str message) noexcept;
+void debug(::rust::Str message) noexcept;
+void trace(::rust::Str message) noexcept;
+} // namespace log
+
+namespace clock {
+std::uint64_t now() noexcept;
+std::uint64_t to_nanos(std::uint64_t time) noexcept;
+std::uint64_t from_nanos(std::uint64_t nanos) noexcept;
+std::uint64_t elapsed_ns(std::uint64_t time) noexcept;
+} // namespace clock
+
+namespace metrics {
+void register_counter(::rust::Str name, ::rust::Str help);
+void register_gauge(::rust::Str name, ::rust::Str help);
+void increment(::rust::Str name, double value);
+void set(::rust::Str name, double value);
+} // namespace metrics
+
+namespace metadata {
+void register_ports(::rust::Str ports) noexcept;
+} // namespace metadata
+
+} // namespace flow
+} // namespace zenoh
@@ -0,0 +1,309 @@
+//
+// Copyright (c) 2022 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+#include <algorithm>
+#include <cctype>
+#include <stdexcept>
+#include <utility>
+
+#include <mock.hpp>
+
+namespace zenoh {
+namespace flow {
+
+namespace {
+
+enum class MetricKind { Counter, Gauge };
+
+struct Metric {
+  MetricKind kind;
+  double value;
+};
+
+//
+// /!\ NOTE: the prefix and the validation of the names must match the ones in
+// `vendor/wrapper/src/metrics.rs`.
+//
+const std::string METRICS_PREFIX = "zenoh_flow_cxx";
+
+std::vector<mock::Record> &records() {
+  static std::vector<mock::Record> records;
+  return records;
+}
+
+std::map<std::string, Metric> &registry() {
+  static std::map<std::string, Metric> registry;
+  return registry;
+}
+
+// The time of the mock, in the representation of the timestamps.
+std::uint64_t &current_time() {
+  static std::uint64_t time = 0;
+  return time;
+}
+
+#if defined(ZENOH_FLOW_SINK)
+Latency &latency() {
+  static Latency latency{0, 0, 0, 0};
+  return latency;
+}
+#endif
+
+void record(mock::Level level, ::rust::Str message) {
+  records().push_back({level, std::string(message)});
+}
+
+bool is_valid_name(const std::string &name) {
+  if (name.empty()) {
+    return false;
+  }
+  auto first = static_cast<unsigned char>(name[0]);
+  if (!std::isalpha(first) && first != '_' && first != ':') {
+    return false;
+  }
+  return std::all_of(name.begin(), name.end(), [](char c) {
+    auto u = static_cast<unsigned char>(c);
+    return std::isalnum(u) || c == '_' || c == ':';
+  });
+}
+
+void register_metric(::rust::Str name, MetricKind kind) {
+  auto key = std::string(name);
+  if (!is_valid_name(key) || key.compare(0, METRICS_PREFIX.size(),
+                                         METRICS_PREFIX) == 0) {
+    throw ::rust::Error("Invalid metric name `" + key + "`");
+  }
+
+  auto found = registry().find(key);
+  if (found == registry().end()) {
+    registry().emplace(key, Metric{kind, 0.0});
+  } else if (found->second.kind != kind) {
+    throw ::rust::Error("Metric `" + key + "` is already registered as a " +
+                        (found->second.kind == MetricKind::Counter ? "Counter"
+                                                                   : "Gauge"));
+  }
+}
+
+Metric &find_metric(::rust::Str name) {
+  auto found = registry().find(std::string(name));
+  if (found == registry().end()) {
+    throw ::rust::Error("Unknown metric `" + std::string(name) + "`");
+  }
+  return found->second;
+}
+
+} // namespace
+
+/*
+ *
+ * Functions of the glue, see `wrapper.hpp`.
+ *
+ */
+
+namespace log {
+void error(::rust::Str message) noexcept { record(mock::Level::Error, message); }
+void warn(::rust::Str message) noexcept { record(mock::Level::Warn, message); }
+void info(::rust::Str message) noexcept { record(mock::Level::Info, message); }
+void debug(::rust::Str message) noexcept { record(mock::Level::Debug, message); }
+void trace(::rust::Str message) noexcept { record(mock::Level::Trace, message); }
+} // namespace log
+
+namespace clock {
+std::uint64_t now() noexcept { return current_time(); }
+
+std::uint64_t to_nanos(std::uint64_t time) noexcept {
+  auto seconds = time >> 32;
+  auto nanos = ((time & 0xFFFFFFFF) * 1000000000) >> 32;
+  return seconds * 1000000000 + nanos;
+}
+
+std::uint64_t from_nanos(std::uint64_t nanos) noexcept {
+  auto seconds = nanos / 1000000000;
+  auto fraction = ((nanos % 1000000000) << 32) / 1000000000;
+  return (seconds << 32) | fraction;
+}
+
+std::uint64_t elapsed_ns(std::uint64_t time) noexcept {
+  auto current = now();
+  return current > time ? to_nanos(current - time) : 0;
+}
+} // namespace clock
+
+namespace metrics {
+void register_counter(::rust::Str name, ::rust::Str) {
+  register_metric(name, MetricKind::Counter);
+}
+
+void register_gauge(::rust::Str name, ::rust::Str) {
+  register_metric(name, MetricKind::Gauge);
+}
+
+void increment(::rust::Str name, double value) {
+  auto &metric = find_metric(name);
+  if (metric.kind == MetricKind::Counter && value < 0.0) {
+    throw ::rust::Error("Counter `" + std::string(name) +
+                        "` cannot be decremented");
+  }
+  metric.value += value;
+}
+
+void set(::rust::Str name, double value) {
+  auto &metric = find_metric(name);
+  if (metric.kind != MetricKind::Gauge) {
+    throw ::rust::Error("Metric `" + std::string(name) + "` is not a gauge");
+  }
+  metric.value = value;
+}
+} // namespace metrics
+
+// The ports are only read from the library of the node, by `zf-cxx inspect`.
+namespace metadata {
+void register_ports(::rust::Str) noexcept {}
+} // namespace metadata
+
+#if defined(ZENOH_FLOW_SINK)
+Latency end_to_end_latency() { return latency(); }
+#endif
+
+namespace mock {
+
+/*
+ *
+ * Mock of the glue.
+ *
+ */
+
+const std::vector<Record> &logs() { return records(); }
+
+void set_time_ns(std::uint64_t nanos) { current_time() = clock::from_nanos(nanos); }
+
+void advance_ns(std::uint64_t nanos) { current_time() += clock::from_nanos(nanos); }
+
+double metric(const std::string &name) { return registry().at(name).value; }
+
+#if defined(ZENOH_FLOW_SINK)
+void set_end_to_end_latency(Latency value) { latency() = value; }
+#endif
+
+void reset() {
+  records().clear();
+  registry().clear();
+  current_time() = 0;
+#if defined(ZENOH_FLOW_SINK)
+  latency() = Latency{0, 0, 0, 0};
+#endif
+}
+
+/*
+ *
+ * Drivers.
+ *
+ */
+
+namespace {
+template <typename Data> ::rust::Vec<std::uint8_t> to_rust(const Data &data) {
+  ::rust::Vec<std::uint8_t> result;
+  result.reserve(data.size());
+  for (auto byte : data) {
+    result.push_back(byte);
+  }
+  return result;
+}
+
+#if defined(ZENOH_FLOW_OPERATOR) || defined(ZENOH_FLOW_SOURCE)
+std::vector<std::uint8_t> from_rust(const ::rust::Vec<std::uint8_t> &data) {
+  return std::vector<std::uint8_t>(data.begin(), data.end());
+}
+#endif
+} // namespace
+
+#if defined(ZENOH_FLOW_OPERATOR)
+
+OperatorDriver::OperatorDriver(std::vector<std::string> inputs,
+                               const std::string &configuration)
+    : context_{0}, state_(initialize(configuration)),
+      inputs_(std::move(inputs)) {}
+
+bool OperatorDriver::receive(const std::string &port,
+                             std::vector<std::uint8_t> data) {
+  return receive(port, std::move(data), clock::now());
+}
+
+bool OperatorDriver::receive(const std::string &port,
+                             std::vector<std::uint8_t> data,
+                             std::uint64_t timestamp) {
+  if (std::find(inputs_.begin(), inputs_.end(), port) == inputs_.end()) {
+    throw std::invalid_argument("Unknown input `" + port + "`");
+  }
+  received_[port] = Input{port, to_rust(data), timestamp, {}};
+
+  ::rust::Vec<InputToken> tokens;
+  for (const auto &input : inputs_) {
+    auto found = received_.find(input);
+    if (found == received_.end()) {
+      tokens.push_back(
+          {TokenStatus::Pending, TokenAction::Wait, input, {}, 0});
+    } else {
+      tokens.push_back({TokenStatus::Ready, TokenAction::Consume, input,
+                        found->second.data, found->second.timestamp});
+    }
+  }
+  if (!input_rule(context_, state_, tokens)) {
+    return false;
+  }
+
+  ::rust::Vec<Input> run_inputs;
+  for (auto &received : received_) {
+    run_inputs.push_back(std::move(received.second));
+  }
+  received_.clear();
+
+  auto run_outputs = run(context_, state_, std::move(run_inputs));
+  auto results = output_rule(context_, state_, std::move(run_outputs),
+                             LocalDeadlineMiss{0, 0, false});
+
+  outputs_.clear();
+  for (const auto &output : results) {
+    outputs_[std::string(output.port_id)] = from_rust(output.data);
+  }
+  return true;
+}
+
+#elif defined(ZENOH_FLOW_SOURCE)
+
+SourceDriver::SourceDriver(const std::string &configuration)
+    : context_{0}, state_(initialize(configuration)) {}
+
+std::vector<std::uint8_t> SourceDriver::run() {
+  return from_rust(::zenoh::flow::run(context_, state_));
+}
+
+#elif defined(ZENOH_FLOW_SINK)
+
+SinkDriver::SinkDriver(const std::string &configuration)
+    : context_{0}, state_(initialize(configuration)) {}
+
+void SinkDriver::run(std::vector<std::uint8_t> data) {
+  run(std::move(data), clock::now());
+}
+
+void SinkDriver::run(std::vector<std::uint8_t> data, std::uint64_t timestamp) {
+  ::zenoh::flow::run(context_, state_, Input{"", to_rust(data), timestamp, {}});
+}
+
+#endif
+
+} // namespace mock
+} // namespace flow
+} // namespace zenoh
@@ -0,0 +1,70 @@
+//
+// Copyright (c) 2022 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+//
+// Tests of the sample operator (`src/operator.cpp`), a counter of ticks.
+//
+
+#include <cstdlib>
+#include <iostream>
+#include <stdexcept>
+
+#include <mock.hpp>
+
+using namespace zenoh::flow;
+
+#define CHECK(condition)                                                       \
+  if (!(condition)) {                                                          \
+    std::cerr << __FILE__ << ":" << __LINE__ << ": " #condition << std::endl;  \
+    std::exit(EXIT_FAILURE);                                                   \
+  }
+
+static void counts_the_ticks() {
+  mock::reset();
+  mock::OperatorDriver driver({"tick"});
+
+  for (std::uint8_t count = 1; count <= 3; count++) {
+    CHECK(driver.receive("tick", {1}));
+    CHECK(driver.outputs().at("count") == std::vector<std::uint8_t>{count});
+  }
+}
+
+static void logs_the_configuration() {
+  mock::reset();
+  mock::OperatorDriver driver({"tick"}, "{\"step\":1}");
+
+  CHECK(mock::logs().size() == 1);
+  CHECK(mock::logs()[0].level == mock::Level::Info);
+  CHECK(mock::logs()[0].message == "Configuration: {\"step\":1}");
+}
+
+static void rejects_an_unknown_port() {
+  mock::reset();
+  mock::OperatorDriver driver({"tick"});
+
+  bool rejected = false;
+  try {
+    driver.receive("tock", {1});
+  } catch (const std::invalid_argument &) {
+    rejected = true;
+  }
+  CHECK(rejected);
+}
+
+int main() {
+  counts_the_ticks();
+  logs_the_configuration();
+  rejects_an_unknown_port();
+  return EXIT_SUCCESS;
+}
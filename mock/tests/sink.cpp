@@ -0,0 +1,45 @@
+//
+// Copyright (c) 2022 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+//
+// Tests of the sample sink (`src/sink.cpp`), a logger of the received data.
+//
+
+#include <cstdlib>
+#include <iostream>
+
+#include <mock.hpp>
+
+using namespace zenoh::flow;
+
+#define CHECK(condition)                                                       \
+  if (!(condition)) {                                                          \
+    std::cerr << __FILE__ << ":" << __LINE__ << ": " #condition << std::endl;  \
+    std::exit(EXIT_FAILURE);                                                   \
+  }
+
+static void logs_the_data() {
+  mock::reset();
+  mock::SinkDriver driver;
+
+  driver.run({1, 2});
+  CHECK(mock::logs().size() == 1);
+  CHECK(mock::logs()[0].level == mock::Level::Info);
+  CHECK(mock::logs()[0].message == "Received: 12");
+}
+
+int main() {
+  logs_the_data();
+  return EXIT_SUCCESS;
+}
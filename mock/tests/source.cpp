@@ -0,0 +1,42 @@
+//
+// Copyright (c) 2022 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+//
+// Tests of the sample source (`src/source.cpp`), a producer of ticks.
+//
+
+#include <cstdlib>
+#include <iostream>
+
+#include <mock.hpp>
+
+using namespace zenoh::flow;
+
+#define CHECK(condition)                                                       \
+  if (!(condition)) {                                                          \
+    std::cerr << __FILE__ << ":" << __LINE__ << ": " #condition << std::endl;  \
+    std::exit(EXIT_FAILURE);                                                   \
+  }
+
+static void produces_a_tick() {
+  mock::reset();
+  mock::SourceDriver driver;
+
+  CHECK(driver.run() == std::vector<std::uint8_t>{1});
+}
+
+int main() {
+  produces_a_tick();
+  return EXIT_SUCCESS;
+}
@@ -20,8 +20,6 @@
 #include <string>
 #include <vector>
 
-#include <iostream>
-
 namespace zenoh {
 namespace flow {
 
@@ -32,8 +30,7 @@ void State::increaseCounter(void) { counter += 1; }
 std::uint8_t State::getCounter(void) { return counter; }
 
 std::unique_ptr<State> initialize(rust::Str json_configuration) {
-  std::cout << "Configuration: " << std::endl;
-  std::cout << json_configuration.data() << '\0' << std::endl;
+  log::info("Configuration: " + std::string(json_configuration));
   return std::make_unique<State>();
 }
 
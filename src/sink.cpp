@@ -12,8 +12,8 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 
-#include <iostream>
 #include <sink.hpp>
+#include <string>
 
 namespace zenoh {
 namespace flow {
@@ -29,12 +29,11 @@ std::unique_ptr<State> initialize(rust::Str json_configuration) {
 
 void
 run(Context &context, std::unique_ptr<State> &state, Input input) {
-  std::cout << "Received: " << std::endl;
-  std::cout << "\t";
+  std::string received = "Received: ";
   for (unsigned char c : input.data) {
-    std::cout << unsigned(c);
+    received += std::to_string(unsigned(c));
   }
-  std::cout << std::endl << std::flush;
+  log::info(received);
 }
 
 } // namespace flow